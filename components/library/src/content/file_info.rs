@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+/// Contains all the information about the actual file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileInfo {
+    /// The full path to the .md file
+    pub path: PathBuf,
+    /// The on-disk filename, without the extension
+    pub name: String,
+    /// The .md path, starting from the content directory, with `/` slashes
+    pub relative: String,
+    /// Path of the directory containing the .md file
+    pub parent: PathBuf,
+    /// The folder names from `content` to the file
+    pub components: Vec<String>,
+    /// The relative path, with any `.{lang}` suffix stripped out.
+    /// Used to match a page/section to its translations in other languages.
+    pub canonical: PathBuf,
+}
+
+impl FileInfo {
+    pub fn new(path: &Path, base_path: &Path) -> FileInfo {
+        let file_path = path.to_path_buf();
+        let name = path.file_stem().unwrap().to_string_lossy().to_string();
+        let parent = path.parent().unwrap().to_path_buf();
+        let relative = path
+            .strip_prefix(base_path)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let components = parent
+            .strip_prefix(base_path)
+            .unwrap_or(&parent)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .filter(|c| !c.is_empty())
+            .collect();
+
+        FileInfo {
+            path: file_path,
+            canonical: PathBuf::from(&relative),
+            name,
+            relative,
+            parent,
+            components,
+        }
+    }
+
+    /// Looks at the filename and extracts the language out of it, if any.
+    /// A file named `about.fr.md` is going to be in French while `about.md`
+    /// will be the default language.
+    /// Also updates `self.canonical` to the name without the language.
+    pub fn find_language(&mut self, languages: &[String], default_language: &str) -> String {
+        let mut parts: Vec<&str> = self.name.splitn(2, '.').collect();
+        if parts.len() == 2 && languages.contains(&parts[1].to_string()) {
+            let lang = parts.remove(1).to_string();
+            let mut canonical = Path::new(&self.relative).with_file_name(parts[0]);
+            if let Some(ext) = self.path.extension() {
+                canonical.set_extension(ext);
+            }
+            self.canonical = canonical;
+            lang
+        } else {
+            default_language.to_string()
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod file_info;
+pub mod page;
+pub mod section;
+pub mod ser;
+
+pub use self::page::Page;
+pub use self::section::Section;
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use tera::{Map, Value};
+
+use content::file_info::FileInfo;
+use content::ser::SerializingPage;
+use library::{Key, Library};
+use rendering::Header;
+
+/// The front matter fields we pull out of a page's metadata. Parsing the
+/// raw TOML/YAML block into this struct happens during page loading; only
+/// the fields consumed by the rest of the pipeline (templates, sorting,
+/// serializing) live here.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PageFrontMatter {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub date: Option<String>,
+    /// `(year, month, day)`, parsed once from `date` so templates and sorting
+    /// don't need to re-parse it.
+    pub datetime_tuple: Option<(i32, u32, u32)>,
+    pub taxonomies: HashMap<String, Vec<String>>,
+    pub draft: bool,
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Page {
+    pub file: FileInfo,
+    pub meta: PageFrontMatter,
+    pub lang: String,
+    /// The HTML rendered from the page's markdown content.
+    pub content: String,
+    /// `content` with markup stripped via `rendering::strip_html`, for
+    /// search indexes/excerpts.
+    pub plain_text: String,
+    pub summary: Option<String>,
+    /// `summary` with markup stripped via `rendering::strip_html`.
+    pub plain_summary: Option<String>,
+    pub permalink: String,
+    pub slug: String,
+    pub path: String,
+    pub components: Vec<String>,
+    pub word_count: Option<usize>,
+    pub reading_time: Option<usize>,
+    pub toc: Vec<Header>,
+    pub serialized_assets: Vec<String>,
+    /// The relative path of every other page/section this page links to via
+    /// an internal (`@/...`) link, as resolved by `rendering` while rendering
+    /// `content`. `Library::populate_backlinks` consumes this to build the
+    /// reverse map that backs `get_backlinks`.
+    pub internal_links: Vec<String>,
+    /// The sections themselves, in ascending order from the index section.
+    pub ancestors: Vec<Key>,
+    pub lighter: Option<Key>,
+    pub heavier: Option<Key>,
+    pub earlier: Option<Key>,
+    pub later: Option<Key>,
+    /// Other pages sharing the most taxonomy terms with this one, ranked by
+    /// overlap count. Computed and memoized by `Library::populate_related_pages`.
+    pub related: Vec<Key>,
+}
+
+impl Page {
+    pub fn is_draft(&self) -> bool {
+        self.meta.draft
+    }
+
+    pub fn to_serialized<'a>(&'a self, library: &'a Library) -> SerializingPage<'a> {
+        SerializingPage::from_page(self, library)
+    }
+
+    pub fn to_serialized_basic<'a>(&'a self, library: &'a Library) -> SerializingPage<'a> {
+        SerializingPage::from_page_basic(self, Some(library))
+    }
+}
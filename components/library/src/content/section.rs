@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use tera::Value;
+
+use content::file_info::FileInfo;
+use content::ser::SerializingSection;
+use library::{Key, Library};
+use rendering::Header;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SectionFrontMatter {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub extra: HashMap<String, Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Section {
+    pub file: FileInfo,
+    pub meta: SectionFrontMatter,
+    pub lang: String,
+    pub content: String,
+    /// `content` with markup stripped via `rendering::strip_html`, for
+    /// search indexes/excerpts.
+    pub plain_text: String,
+    pub permalink: String,
+    pub path: String,
+    pub components: Vec<String>,
+    pub word_count: Option<usize>,
+    pub reading_time: Option<usize>,
+    pub toc: Vec<Header>,
+    pub serialized_assets: Vec<String>,
+    /// The relative path of every other page/section this section links to
+    /// via an internal (`@/...`) link, as resolved by `rendering` while
+    /// rendering `content`. `Library::populate_backlinks` consumes this the
+    /// same way it does `Page::internal_links`.
+    pub internal_links: Vec<String>,
+    pub ancestors: Vec<Key>,
+    pub pages: Vec<Key>,
+    pub subsections: Vec<Key>,
+}
+
+impl Section {
+    pub fn to_serialized<'a>(&'a self, library: &'a Library) -> SerializingSection<'a> {
+        SerializingSection::from_section(self, library)
+    }
+
+    pub fn to_serialized_basic<'a>(&'a self, library: &'a Library) -> SerializingSection<'a> {
+        SerializingSection::from_section_basic(self, Some(library))
+    }
+}
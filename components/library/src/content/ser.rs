@@ -11,6 +11,7 @@ use rendering::Header;
 pub struct SerializingPage<'a> {
     relative_path: &'a str,
     content: &'a str,
+    plain_text: &'a str,
     permalink: &'a str,
     slug: &'a str,
     ancestors: Vec<String>,
@@ -25,6 +26,7 @@ pub struct SerializingPage<'a> {
     path: &'a str,
     components: &'a [String],
     summary: &'a Option<String>,
+    plain_summary: &'a Option<String>,
     word_count: Option<usize>,
     reading_time: Option<usize>,
     toc: &'a [Header],
@@ -34,6 +36,9 @@ pub struct SerializingPage<'a> {
     heavier: Option<Box<SerializingPage<'a>>>,
     earlier: Option<Box<SerializingPage<'a>>>,
     later: Option<Box<SerializingPage<'a>>>,
+    related: Vec<SerializingPage<'a>>,
+    backlinks: Vec<&'a str>,
+    translations: Vec<SerializingPage<'a>>,
 }
 
 impl<'a> SerializingPage<'a> {
@@ -65,11 +70,23 @@ impl<'a> SerializingPage<'a> {
             .iter()
             .map(|k| library.get_section_by_key(*k).file.relative.clone())
             .collect();
+        let related = page
+            .related
+            .iter()
+            .map(|k| Self::from_page_basic(pages.get(k).unwrap(), Some(library)))
+            .collect();
+        let backlinks = library.get_backlinks(&page.file.relative);
+        let translations = library
+            .find_translations(&page.file.canonical, &page.lang)
+            .iter()
+            .map(|k| Self::from_page_basic(pages.get(k).unwrap(), Some(library)))
+            .collect();
 
         SerializingPage {
             relative_path: &page.file.relative,
             ancestors,
             content: &page.content,
+            plain_text: &page.plain_text,
             permalink: &page.permalink,
             slug: &page.slug,
             title: &page.meta.title,
@@ -83,6 +100,7 @@ impl<'a> SerializingPage<'a> {
             path: &page.path,
             components: &page.components,
             summary: &page.summary,
+            plain_summary: &page.plain_summary,
             word_count: page.word_count,
             reading_time: page.reading_time,
             toc: &page.toc,
@@ -92,6 +110,9 @@ impl<'a> SerializingPage<'a> {
             heavier,
             earlier,
             later,
+            related,
+            backlinks,
+            translations,
         }
     }
 
@@ -118,6 +139,7 @@ impl<'a> SerializingPage<'a> {
             relative_path: &page.file.relative,
             ancestors,
             content: &page.content,
+            plain_text: &page.plain_text,
             permalink: &page.permalink,
             slug: &page.slug,
             title: &page.meta.title,
@@ -131,6 +153,7 @@ impl<'a> SerializingPage<'a> {
             path: &page.path,
             components: &page.components,
             summary: &page.summary,
+            plain_summary: &page.plain_summary,
             word_count: page.word_count,
             reading_time: page.reading_time,
             toc: &page.toc,
@@ -140,6 +163,9 @@ impl<'a> SerializingPage<'a> {
             heavier: None,
             earlier: None,
             later: None,
+            related: vec![],
+            backlinks: vec![],
+            translations: vec![],
         }
     }
 }
@@ -148,6 +174,7 @@ impl<'a> SerializingPage<'a> {
 pub struct SerializingSection<'a> {
     relative_path: &'a str,
     content: &'a str,
+    plain_text: &'a str,
     permalink: &'a str,
     ancestors: Vec<String>,
     title: &'a Option<String>,
@@ -161,6 +188,8 @@ pub struct SerializingSection<'a> {
     assets: &'a [String],
     pages: Vec<SerializingPage<'a>>,
     subsections: Vec<&'a str>,
+    backlinks: Vec<&'a str>,
+    translations: Vec<SerializingSection<'a>>,
 }
 
 impl<'a> SerializingSection<'a> {
@@ -181,11 +210,18 @@ impl<'a> SerializingSection<'a> {
             .iter()
             .map(|k| library.get_section_by_key(*k).file.relative.clone())
             .collect();
+        let backlinks = library.get_backlinks(&section.file.relative);
+        let translations = library
+            .find_section_translations(&section.file.canonical, &section.lang)
+            .iter()
+            .map(|k| Self::from_section_basic(library.get_section_by_key(*k), Some(library)))
+            .collect();
 
         SerializingSection {
             relative_path: &section.file.relative,
             ancestors,
             content: &section.content,
+            plain_text: &section.plain_text,
             permalink: &section.permalink,
             title: &section.meta.title,
             description: &section.meta.description,
@@ -198,6 +234,8 @@ impl<'a> SerializingSection<'a> {
             assets: &section.serialized_assets,
             pages,
             subsections,
+            backlinks,
+            translations,
         }
     }
 
@@ -217,6 +255,7 @@ impl<'a> SerializingSection<'a> {
             relative_path: &section.file.relative,
             ancestors,
             content: &section.content,
+            plain_text: &section.plain_text,
             permalink: &section.permalink,
             title: &section.meta.title,
             description: &section.meta.description,
@@ -229,6 +268,8 @@ impl<'a> SerializingSection<'a> {
             assets: &section.serialized_assets,
             pages: vec![],
             subsections: vec![],
+            backlinks: vec![],
+            translations: vec![],
         }
     }
 }
@@ -0,0 +1,12 @@
+extern crate slotmap;
+extern crate tera;
+#[macro_use]
+extern crate serde_derive;
+
+extern crate rendering;
+
+pub mod content;
+mod library;
+
+pub use content::{Page, Section};
+pub use library::{Key, Library, Pages, Sections};
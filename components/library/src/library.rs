@@ -0,0 +1,353 @@
+use slotmap::DenseSlotMap;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use content::{Page, Section};
+
+slotmap::new_key_type! {
+    pub struct Key;
+}
+
+pub type Pages = DenseSlotMap<Key, Page>;
+pub type Sections = DenseSlotMap<Key, Section>;
+
+/// Default cap on how many related pages we compute per page.
+const DEFAULT_MAX_RELATED_PAGES: usize = 5;
+
+/// Everything we have learned about a site's content: all of its pages and
+/// sections, plus indexes computed over them (related pages, backlinks,
+/// translations) so templates don't have to walk the whole site themselves.
+#[derive(Debug)]
+pub struct Library {
+    pages: Pages,
+    sections: Sections,
+    paths_to_pages: HashMap<PathBuf, Key>,
+    paths_to_sections: HashMap<PathBuf, Key>,
+    /// Reverse map of target path (page or section) -> pages linking to it,
+    /// built by `populate_backlinks` from the internal links `rendering`
+    /// recorded while rendering each page's content.
+    backlinks: HashMap<Key, Vec<Key>>,
+    /// canonical path (language-suffix stripped) -> lang -> key, used to find
+    /// the sibling-language version of a page/section.
+    translations_pages: HashMap<PathBuf, HashMap<String, Key>>,
+    translations_sections: HashMap<PathBuf, HashMap<String, Key>>,
+    /// The site's configured languages, in configured order, so translations
+    /// come back in a deterministic order instead of `HashMap` iteration
+    /// order.
+    languages: Vec<String>,
+}
+
+impl Library {
+    pub fn new(pages: Pages, sections: Sections, languages: Vec<String>) -> Self {
+        let mut paths_to_pages = HashMap::with_capacity(pages.len());
+        for (key, page) in &pages {
+            paths_to_pages.insert(PathBuf::from(&page.file.relative), key);
+        }
+        let mut paths_to_sections = HashMap::with_capacity(sections.len());
+        for (key, section) in &sections {
+            paths_to_sections.insert(PathBuf::from(&section.file.relative), key);
+        }
+
+        Library {
+            pages,
+            sections,
+            paths_to_pages,
+            paths_to_sections,
+            backlinks: HashMap::new(),
+            translations_pages: HashMap::new(),
+            translations_sections: HashMap::new(),
+            languages,
+        }
+    }
+
+    pub fn pages(&self) -> &Pages {
+        &self.pages
+    }
+
+    pub fn pages_mut(&mut self) -> &mut Pages {
+        &mut self.pages
+    }
+
+    pub fn sections(&self) -> &Sections {
+        &self.sections
+    }
+
+    pub fn get_page_by_key(&self, key: Key) -> &Page {
+        &self.pages[key]
+    }
+
+    pub fn get_section_by_key(&self, key: Key) -> &Section {
+        &self.sections[key]
+    }
+
+    pub fn get_section_path_by_key(&self, key: Key) -> &str {
+        &self.sections[key].path
+    }
+
+    /// Computes, for every page, the other pages that share the most
+    /// taxonomy terms with it (tags, categories, ...), ranked by overlap
+    /// count and capped at `max_related`. The result is stored back on each
+    /// `Page` as `related`, the same way sibling pages are memoized on
+    /// `lighter`/`heavier`/`earlier`/`later`.
+    pub fn populate_related_pages(&mut self, max_related: usize) {
+        let keys: Vec<Key> = self.pages.keys().collect();
+        let mut related_by_key = HashMap::with_capacity(keys.len());
+
+        for &key in &keys {
+            let terms = &self.pages[key].meta.taxonomies;
+            if terms.is_empty() {
+                related_by_key.insert(key, Vec::new());
+                continue;
+            }
+
+            let mut scored: Vec<(Key, usize)> = keys
+                .iter()
+                .filter(|&&other| other != key)
+                .filter_map(|&other| {
+                    let overlap = overlapping_terms(terms, &self.pages[other].meta.taxonomies);
+                    if overlap > 0 {
+                        Some((other, overlap))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            scored.sort_by(|a, b| b.1.cmp(&a.1));
+            scored.truncate(max_related);
+            related_by_key.insert(key, scored.into_iter().map(|(k, _)| k).collect());
+        }
+
+        for (key, related) in related_by_key {
+            self.pages[key].related = related;
+        }
+    }
+
+    pub fn populate_related_pages_default(&mut self) {
+        self.populate_related_pages(DEFAULT_MAX_RELATED_PAGES);
+    }
+
+    /// Rebuilds the backlinks reverse map from recorded internal links.
+    pub fn populate_backlinks(&mut self) {
+        let mut backlinks: HashMap<Key, Vec<Key>> = HashMap::new();
+
+        for (source, page) in &self.pages {
+            for link in &page.internal_links {
+                if let Some(&target) = self.resolve_internal_link(link) {
+                    backlinks.entry(target).or_insert_with(Vec::new).push(source);
+                }
+            }
+        }
+        for (source, section) in &self.sections {
+            for link in &section.internal_links {
+                if let Some(&target) = self.resolve_internal_link(link) {
+                    backlinks.entry(target).or_insert_with(Vec::new).push(source);
+                }
+            }
+        }
+
+        self.backlinks = backlinks;
+    }
+
+    fn resolve_internal_link(&self, relative_path: &str) -> Option<&Key> {
+        self.paths_to_pages
+            .get(Path::new(relative_path))
+            .or_else(|| self.paths_to_sections.get(Path::new(relative_path)))
+    }
+
+    /// The relative path of every page/section whose rendered content links
+    /// to the page/section at `relative_path`, i.e. "what links here".
+    pub fn get_backlinks(&self, relative_path: &str) -> Vec<&str> {
+        let target = match self.resolve_internal_link(relative_path) {
+            Some(target) => target,
+            None => return Vec::new(),
+        };
+
+        match self.backlinks.get(target) {
+            Some(sources) => sources
+                .iter()
+                .filter_map(|k| {
+                    self.pages
+                        .get(*k)
+                        .map(|p| p.file.relative.as_str())
+                        .or_else(|| self.sections.get(*k).map(|s| s.file.relative.as_str()))
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Indexes every page/section by canonical path and language. Call once
+    /// all pages/sections have been loaded.
+    pub fn populate_translations(&mut self) {
+        let mut translations_pages: HashMap<PathBuf, HashMap<String, Key>> = HashMap::new();
+        for (key, page) in &self.pages {
+            translations_pages
+                .entry(page.file.canonical.clone())
+                .or_insert_with(HashMap::new)
+                .insert(page.lang.clone(), key);
+        }
+
+        let mut translations_sections: HashMap<PathBuf, HashMap<String, Key>> = HashMap::new();
+        for (key, section) in &self.sections {
+            translations_sections
+                .entry(section.file.canonical.clone())
+                .or_insert_with(HashMap::new)
+                .insert(section.lang.clone(), key);
+        }
+
+        self.translations_pages = translations_pages;
+        self.translations_sections = translations_sections;
+    }
+
+    /// The pages sharing `canonical`'s path in every language other than
+    /// `current_lang`, in the site's configured language order.
+    pub fn find_translations(&self, canonical: &Path, current_lang: &str) -> Vec<Key> {
+        translations_in_language_order(&self.translations_pages, &self.languages, canonical, current_lang)
+    }
+
+    /// Same as `find_translations` but for sections.
+    pub fn find_section_translations(&self, canonical: &Path, current_lang: &str) -> Vec<Key> {
+        translations_in_language_order(&self.translations_sections, &self.languages, canonical, current_lang)
+    }
+}
+
+/// Looks up `canonical` in `by_canonical` and returns its keys for every
+/// language other than `current_lang`, ordered per `languages` rather than
+/// `HashMap` iteration order. A translation in a language missing from
+/// `languages` is appended after the configured ones.
+fn translations_in_language_order(
+    by_canonical: &HashMap<PathBuf, HashMap<String, Key>>,
+    languages: &[String],
+    canonical: &Path,
+    current_lang: &str,
+) -> Vec<Key> {
+    let by_lang = match by_canonical.get(canonical) {
+        Some(by_lang) => by_lang,
+        None => return Vec::new(),
+    };
+
+    let mut keys: Vec<Key> = languages
+        .iter()
+        .filter(|lang| lang.as_str() != current_lang)
+        .filter_map(|lang| by_lang.get(lang))
+        .cloned()
+        .collect();
+
+    for (lang, &key) in by_lang {
+        if lang.as_str() != current_lang && !languages.iter().any(|l| l == lang) {
+            keys.push(key);
+        }
+    }
+
+    keys
+}
+
+/// Counts how many taxonomy terms two pages have in common, across all of
+/// their taxonomies (a shared tag counts the same as a shared category).
+fn overlapping_terms(
+    a: &HashMap<String, Vec<String>>,
+    b: &HashMap<String, Vec<String>>,
+) -> usize {
+    a.iter()
+        .map(|(taxonomy, terms)| {
+            let other_terms = match b.get(taxonomy) {
+                Some(t) => t,
+                None => return 0,
+            };
+            terms.iter().filter(|t| other_terms.contains(t)).count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use content::file_info::FileInfo;
+    use content::{Page, Section};
+
+    fn page(relative: &str, lang: &str) -> Page {
+        Page {
+            file: FileInfo { relative: relative.to_string(), canonical: PathBuf::from(relative), ..FileInfo::default() },
+            lang: lang.to_string(),
+            ..Page::default()
+        }
+    }
+
+    fn section(relative: &str, lang: &str) -> Section {
+        Section {
+            file: FileInfo { relative: relative.to_string(), canonical: PathBuf::from(relative), ..FileInfo::default() },
+            lang: lang.to_string(),
+            ..Section::default()
+        }
+    }
+
+    #[test]
+    fn backlinks_include_pages_and_sections() {
+        let mut pages = Pages::default();
+        let mut sections = Sections::default();
+
+        pages.insert(page("target.md", "en"));
+
+        let mut linking_page = page("from-page.md", "en");
+        linking_page.internal_links.push("target.md".to_string());
+        pages.insert(linking_page);
+
+        let mut linking_section = section("from-section/_index.md", "en");
+        linking_section.internal_links.push("target.md".to_string());
+        sections.insert(linking_section);
+
+        let mut library = Library::new(pages, sections, vec!["en".to_string()]);
+        library.populate_backlinks();
+
+        let mut backlinks = library.get_backlinks("target.md");
+        backlinks.sort();
+        assert_eq!(backlinks, vec!["from-page.md", "from-section/_index.md"]);
+    }
+
+    #[test]
+    fn related_pages_ranks_by_taxonomy_overlap_and_caps() {
+        let mut pages = Pages::default();
+
+        let mut a = page("a.md", "en");
+        a.meta.taxonomies.insert("tags".to_string(), vec!["rust".to_string(), "cli".to_string()]);
+        let mut b = page("b.md", "en");
+        b.meta.taxonomies.insert("tags".to_string(), vec!["rust".to_string()]);
+        let mut c = page("c.md", "en");
+        c.meta.taxonomies.insert("tags".to_string(), vec!["rust".to_string(), "cli".to_string()]);
+        let d = page("d.md", "en");
+
+        let key_a = pages.insert(a);
+        pages.insert(b);
+        let key_c = pages.insert(c);
+        pages.insert(d);
+
+        let mut library = Library::new(pages, Sections::default(), vec!["en".to_string()]);
+        library.populate_related_pages(1);
+
+        assert_eq!(library.get_page_by_key(key_a).related, vec![key_c]);
+    }
+
+    #[test]
+    fn find_translations_orders_by_configured_languages_and_excludes_current() {
+        let mut pages = Pages::default();
+        let fr = pages.insert(page("about.fr.md", "fr"));
+        let de = pages.insert(page("about.de.md", "de"));
+        let en = pages.insert(page("about.md", "en"));
+        for key in &[fr, de, en] {
+            pages[*key].file.canonical = PathBuf::from("about.md");
+        }
+
+        let mut library = Library::new(
+            pages,
+            Sections::default(),
+            vec!["en".to_string(), "fr".to_string(), "de".to_string()],
+        );
+        library.populate_translations();
+
+        let translations = library.find_translations(&PathBuf::from("about.md"), "en");
+        assert_eq!(translations, vec![fr, de]);
+    }
+}
@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate serde_derive;
+
+/// One entry in a page/section's table of contents, built from its markdown
+/// headings.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Header {
+    pub level: u32,
+    pub id: String,
+    pub permalink: String,
+    pub title: String,
+    pub children: Vec<Header>,
+}
+
+/// The result of rendering a page/section's markdown content.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Rendered {
+    pub body: String,
+    pub toc: Vec<Header>,
+    pub word_count: Option<usize>,
+    pub reading_time: Option<usize>,
+    /// The relative path (`relative_path` of the target `Page`/`Section`) of
+    /// every internal (`@/...`) link resolved while rendering this content.
+    /// Used by `Library` to build the backlinks reverse map.
+    pub internal_links: Vec<String>,
+}
+
+/// Strips HTML markup from rendered content, leaving the plain-text body
+/// `Page`/`Section` store alongside `content` for search indexes and
+/// excerpts (`plain_text`/`plain_summary`).
+pub fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut chars = html.chars().peekable();
+    let mut in_tag = false;
+    let mut quote = None;
+
+    while let Some(c) = chars.next() {
+        if in_tag {
+            match quote {
+                // `>` inside a quoted attribute value doesn't close the tag.
+                Some(q) if c == q => quote = None,
+                Some(_) => {}
+                None => match c {
+                    '"' | '\'' => quote = Some(c),
+                    '>' => in_tag = false,
+                    _ => {}
+                },
+            }
+            continue;
+        }
+
+        match c {
+            '<' => in_tag = true,
+            '&' => text.push_str(&decode_entity(&mut chars)),
+            _ => text.push(c),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Decodes a single HTML entity right after the `&` that `strip_html`
+/// already consumed, advancing `chars` past it. Falls back to `&` plus
+/// whatever was consumed if it isn't one of the handful of named/numeric
+/// entities we recognize.
+fn decode_entity<I: Iterator<Item = char>>(chars: &mut ::std::iter::Peekable<I>) -> String {
+    let mut entity = String::new();
+    while let Some(&c) = chars.peek() {
+        if c == ';' {
+            entity.push(c);
+            chars.next();
+            break;
+        }
+        if !c.is_alphanumeric() && c != '#' {
+            break;
+        }
+        entity.push(c);
+        chars.next();
+    }
+
+    match entity.as_str() {
+        "amp;" => "&".to_string(),
+        "lt;" => "<".to_string(),
+        "gt;" => ">".to_string(),
+        "quot;" => "\"".to_string(),
+        "apos;" | "#39;" => "'".to_string(),
+        _ => format!("&{}", entity),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_html;
+
+    #[test]
+    fn strips_tags() {
+        assert_eq!(strip_html("<p>Hello <strong>world</strong></p>"), "Hello world");
+    }
+
+    #[test]
+    fn keeps_gt_inside_quoted_attribute() {
+        assert_eq!(strip_html(r#"<a title="a > b">text</a>"#), "text");
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(strip_html("Tom &amp; Jerry &lt;3"), "Tom & Jerry <3");
+    }
+}